@@ -14,7 +14,7 @@
 //! // Spawns three new child processes and adds them to the supervisor.
 //! for _ in 0..3 {
 //!     let child = process::Command::new("my-subcommand").spawn().unwrap();
-//!     supervisor.add_child(child);
+//!     supervisor.add_child(child, None);
 //! }
 //!
 //! // Terminate all child processes, waiting for each to be completed or killed.
@@ -23,36 +23,667 @@
 
 use nix::sys::signal;
 use nix::unistd;
-use std::{io, process, sync, thread, time};
+use std::{fmt, io, process, sync, thread, time};
+
+/// The action to take when a supervised child process exits.
+///
+/// Modelled after the Erlang/OTP restart types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Always restart the child, regardless of how it exited.
+    Permanent,
+    /// Restart the child only if it terminated abnormally, i.e. with a non-zero exit status or
+    /// because it was killed by a signal.
+    Transient,
+    /// Never restart the child.
+    Temporary,
+}
+
+impl RestartPolicy {
+    /// Returns whether a child that exited with `status` should be restarted under this policy.
+    fn should_restart(self, status: process::ExitStatus) -> bool {
+        match self {
+            RestartPolicy::Permanent => true,
+            RestartPolicy::Transient => !status.success(),
+            RestartPolicy::Temporary => false,
+        }
+    }
+}
+
+/// How the supervisor reacts when one of its children dies.
+///
+/// Modelled after the Erlang/OTP supervision strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Restart only the child that died.
+    OneForOne,
+    /// Terminate every child and restart the whole set.
+    OneForAll,
+    /// Restart the dead child and every child added after it, in order.
+    RestForOne,
+}
+
+/// The order in which a [`Supervisor`] tears its children down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownOrder {
+    /// Shut children down one at a time, in the reverse order they were added. Worst-case
+    /// teardown is `N * kill_timeout`.
+    Reverse,
+    /// Signal every child up front, then poll them all concurrently, escalating each to `SIGKILL`
+    /// once its individual deadline passes. Bounds total teardown to roughly one `kill_timeout`.
+    Parallel,
+}
+
+/// A factory that respawns a supervised child process.
+type Respawn = Box<dyn FnMut() -> io::Result<process::Child> + Send>;
+
+/// Identifies a child within a [`Supervisor`], returned from the `add_*` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChildId(u64);
+
+/// How a child terminated during shutdown.
+#[derive(Debug)]
+pub enum ExitOutcome {
+    /// The child exited on its own, either before it was signalled or within the grace period
+    /// after being signalled.
+    Finished(process::ExitStatus),
+    /// The child did not exit within the grace period and had to be `SIGKILL`ed.
+    Killed,
+}
+
+/// Per-child shutdown profile.
+///
+/// Overrides the supervisor-wide `kill_timeout`/`poll_interval` for a single child, allowing some
+/// processes a longer grace window and others an immediate kill. A `kill_timeout` of `None`
+/// selects *brutal kill* mode, sending `SIGKILL` straight away with no grace period.
+#[derive(Debug, Clone)]
+pub struct ShutdownConfig {
+    /// Signal sent first to ask the child to terminate. Ignored in brutal-kill mode.
+    pub term_signal: signal::Signal,
+    /// Grace period before escalating to `SIGKILL`, or `None` for brutal kill.
+    pub kill_timeout: Option<time::Duration>,
+    /// Time between checks if the process has terminated.
+    pub poll_interval: time::Duration,
+}
+
+/// A single supervised child, pairing the running process with its restart specification.
+struct SupervisedChild {
+    /// Stable identifier handed out when the child was added.
+    id: ChildId,
+    /// The currently running process.
+    child: process::Child,
+    /// Factory used to respawn the child, if it was registered as a child spec.
+    factory: Option<Respawn>,
+    /// When the child should be restarted.
+    policy: RestartPolicy,
+    /// Per-child shutdown profile, falling back to the supervisor's settings when absent.
+    shutdown: Option<ShutdownConfig>,
+}
+
+impl fmt::Debug for SupervisedChild {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SupervisedChild")
+            .field("id", &self.id)
+            .field("child", &self.child)
+            .field("factory", &self.factory.as_ref().map(|_| "<fn>"))
+            .field("policy", &self.policy)
+            .field("shutdown", &self.shutdown)
+            .finish()
+    }
+}
 
 /// A supervisor for child processes.
 ///
 /// Supports default, which will result in a `kill_timeout` of 10 seconds.
 ///
+/// Children are added either as plain processes via [`Supervisor::add_child`] or as OTP-style
+/// child specs via [`Supervisor::add_spec`]. A spec pairs a respawn factory with a
+/// [`RestartPolicy`]; running [`Supervisor::run`] then keeps the specced children alive according
+/// to the configured [`Strategy`].
+///
 /// When the supervisor is dropped, it will kill all of its owned child processes using
 /// [`shutdown_process`] in the reverse order they were added, ignoring any errors.
-#[derive(Debug)]
 pub struct Supervisor {
     /// Supervised child processes.
-    children: Vec<process::Child>,
+    children: Vec<SupervisedChild>,
     /// How long to wait before sending SIGKILL after SIGTERM.
     kill_timeout: time::Duration,
     /// Time between checks if process has terminated.
     poll_interval: time::Duration,
+    /// Strategy used by [`Supervisor::run`] when a child dies.
+    strategy: Strategy,
+    /// Maximum number of restarts tolerated within `period` before the run loop gives up.
+    intensity: usize,
+    /// Sliding window over which `intensity` restarts are counted.
+    period: time::Duration,
+    /// Timestamps of recent restarts, used to detect restart storms.
+    restarts: Vec<time::Instant>,
+    /// Source of the next [`ChildId`].
+    next_id: u64,
+    /// PIDs of the currently live children, shared with the signal-forwarding thread.
+    pids: sync::Arc<sync::Mutex<Vec<unistd::Pid>>>,
+    /// Order in which children are terminated on shutdown.
+    order: ShutdownOrder,
+    /// Handles of replaced/removed children that may not have been reaped yet. Drained
+    /// opportunistically on each poll tick to avoid leaving zombies behind.
+    orphans: Vec<process::Child>,
+    /// Clones of the background signal-forwarding threads' iterators, one per
+    /// [`Supervisor::forward_signals`] call. Closed on shutdown so the threads exit instead of
+    /// outliving the supervisor.
+    signal_handles: sync::Mutex<Vec<signal_hook::iterator::Signals>>,
+}
+
+impl fmt::Debug for Supervisor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Supervisor")
+            .field("children", &self.children)
+            .field("kill_timeout", &self.kill_timeout)
+            .field("poll_interval", &self.poll_interval)
+            .field("strategy", &self.strategy)
+            .field("intensity", &self.intensity)
+            .field("period", &self.period)
+            .field("pids", &self.pids)
+            .field("order", &self.order)
+            .field("orphans", &self.orphans)
+            .finish()
+    }
 }
 
 impl Supervisor {
     /// Adds a child process to the supervisor.
-    pub fn add_child(&mut self, child: process::Child) {
-        self.children.push(child)
+    ///
+    /// The child is registered as [`RestartPolicy::Temporary`] without a respawn factory, so
+    /// [`Supervisor::run`] will never bring it back once it exits. An optional [`ShutdownConfig`]
+    /// overrides the supervisor-wide shutdown settings for this child; passing `None` keeps the
+    /// supervisor's `kill_timeout`/`poll_interval`.
+    ///
+    /// Returns the [`ChildId`] assigned to the child.
+    pub fn add_child(&mut self, child: process::Child, shutdown: Option<ShutdownConfig>) -> ChildId {
+        let id = self.next_child_id();
+        self.children.push(SupervisedChild {
+            id,
+            child,
+            factory: None,
+            policy: RestartPolicy::Temporary,
+            shutdown,
+        });
+        self.refresh_pids();
+        id
+    }
+
+    /// Hands out the next [`ChildId`].
+    fn next_child_id(&mut self) -> ChildId {
+        let id = ChildId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// Adds a child spec to the supervisor.
+    ///
+    /// The `factory` is invoked immediately to spawn the initial child and retained so that
+    /// [`Supervisor::run`] can respawn it according to `policy`. Returns the [`ChildId`] assigned
+    /// to the child.
+    pub fn add_spec<F>(&mut self, policy: RestartPolicy, mut factory: F) -> io::Result<ChildId>
+    where
+        F: FnMut() -> io::Result<process::Child> + Send + 'static,
+    {
+        let child = factory()?;
+        let id = self.next_child_id();
+        self.children.push(SupervisedChild {
+            id,
+            child,
+            factory: Some(Box::new(factory)),
+            policy,
+            shutdown: None,
+        });
+        self.refresh_pids();
+        Ok(id)
+    }
+
+    /// Sets the [`Strategy`] used when a child dies, returning the supervisor for chaining.
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the restart intensity guard, returning the supervisor for chaining.
+    ///
+    /// If more than `intensity` restarts happen within `period`, [`Supervisor::run`] terminates
+    /// all remaining children and returns an error instead of restarting again.
+    pub fn with_max_restarts(mut self, intensity: usize, period: time::Duration) -> Self {
+        self.intensity = intensity;
+        self.period = period;
+        self
+    }
+
+    /// Sets the [`ShutdownOrder`], returning the supervisor for chaining.
+    pub fn with_shutdown_order(mut self, order: ShutdownOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Runs the supervision loop until `term` is set or the restart intensity is exceeded.
+    ///
+    /// On each tick the children are polled via `try_wait` in the order they were added, up to the
+    /// first one found to have exited. When a child has exited, the configured
+    /// [`Strategy`] decides which children to restart, respecting each child's [`RestartPolicy`].
+    /// Exceeding the intensity guard terminates all remaining children and returns an error; a
+    /// clean exit via `term` leaves the children to be shut down on drop, in the configured
+    /// [`ShutdownOrder`] (reverse order by default).
+    pub fn run(&mut self, term: &sync::atomic::AtomicBool) -> io::Result<()> {
+        while !term.load(sync::atomic::Ordering::Relaxed) {
+            let mut died = None;
+            for (index, supervised) in self.children.iter_mut().enumerate() {
+                if let Some(status) = supervised.child.try_wait()? {
+                    died = Some((index, status));
+                    break;
+                }
+            }
+
+            if let Some((index, status)) = died {
+                if self.children[index].policy.should_restart(status) {
+                    if let Err(err) = self.restart(index) {
+                        // `restart` failed partway through (the intensity guard tripped, or a
+                        // factory or `try_wait` returned an error), and it may have
+                        // just stashed a replaced/removed child as an orphan. Reap before
+                        // returning so a caller inspecting `orphans` right after a failed `run`
+                        // doesn't see a handle that's actually already been accounted for.
+                        self.reap_orphans();
+                        return Err(err);
+                    }
+                } else {
+                    // Not coming back: drop it so the next tick's scan doesn't see the same
+                    // already-reaped `try_wait` result forever and stall detection of later
+                    // children. Stash the handle so `reap_orphans` still accounts for it.
+                    let dead = self.children.remove(index);
+                    self.orphans.push(dead.child);
+                    self.refresh_pids();
+                }
+            }
+
+            self.reap_orphans();
+            thread::sleep(self.poll_interval);
+        }
+
+        Ok(())
+    }
+
+    /// Restarts the child at `index` (and, depending on the strategy, its siblings).
+    fn restart(&mut self, index: usize) -> io::Result<()> {
+        let affected: Vec<usize> = match self.strategy {
+            Strategy::OneForOne => vec![index],
+            Strategy::OneForAll => (0..self.children.len()).collect(),
+            Strategy::RestForOne => (index..self.children.len()).collect(),
+        };
+
+        // Terminate the still-living siblings first, in reverse order, mirroring the shutdown
+        // semantics used on drop. The child at `index` already exited, so it is skipped here.
+        // Each sibling's own exit status is recorded so its `RestartPolicy` can be re-checked
+        // below: being forced down by a sibling's crash still has to respect `Temporary`'s
+        // "never restart" and `Transient`'s "only on abnormal exit" semantics.
+        let mut forced_status = std::collections::HashMap::new();
+        for &i in affected.iter().rev() {
+            if i != index {
+                if let Ok(status) = self.shutdown_at(i) {
+                    forced_status.insert(i, status);
+                }
+            }
+        }
+
+        // A sibling comes back only if its own policy calls for it. The child at `index`
+        // already exited on its own and had its policy checked by the caller, so it always
+        // comes back here. A forced-down sibling is checked against the status of that forced
+        // shutdown, since a `Temporary` child must never restart and a `Transient` one should
+        // only restart if the forced exit counted as abnormal.
+        let should_restart = |children: &[SupervisedChild], i: usize| -> bool {
+            if i == index {
+                return true;
+            }
+            match forced_status.get(&i) {
+                Some(&status) => children[i].policy.should_restart(status),
+                None => children[i].policy != RestartPolicy::Temporary,
+            }
+        };
+
+        // Respawn every affected child that has a factory and whose policy calls for it, in the
+        // order they were added.
+        for &i in &affected {
+            if self.children[i].factory.is_some() && should_restart(&self.children, i) {
+                self.respawn(i)?;
+            }
+        }
+
+        // Children that aren't coming back — no factory, or a policy that says no — have no way
+        // forward and were just terminated above. Drop them now, highest index first so removal
+        // doesn't shift the still-valid indices earlier in `affected` out from under us;
+        // otherwise their already-exited handle would linger in `self.children` and block
+        // detection of deaths further down the list forever.
+        for &i in affected.iter().rev() {
+            let coming_back = self.children[i].factory.is_some() && should_restart(&self.children, i);
+            if !coming_back {
+                let dead = self.children.remove(i);
+                self.orphans.push(dead.child);
+            }
+        }
+
+        self.refresh_pids();
+        Ok(())
+    }
+
+    /// Respawns the child at `index` via its factory, recording the restart for the guard.
+    fn respawn(&mut self, index: usize) -> io::Result<()> {
+        let new_child = {
+            let factory = self.children[index]
+                .factory
+                .as_mut()
+                .expect("respawn called on a child without a factory");
+            factory()?
+        };
+        // Retain the replaced handle as an orphan so its PID is reaped rather than leaked.
+        let old_child = std::mem::replace(&mut self.children[index].child, new_child);
+        self.orphans.push(old_child);
+        self.record_restart()
+    }
+
+    /// Records a restart and, if the intensity guard is tripped, tears everything down.
+    fn record_restart(&mut self) -> io::Result<()> {
+        let now = time::Instant::now();
+        self.restarts.push(now);
+        self.restarts
+            .retain(|restart| now.duration_since(*restart) <= self.period);
+
+        if self.restarts.len() > self.intensity {
+            for index in (0..self.children.len()).rev() {
+                let _ = self.shutdown_at(index);
+            }
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "restart intensity exceeded: more than {} restarts within {:?}",
+                    self.intensity, self.period
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Opportunistically reaps orphaned child handles.
+    ///
+    /// Orphans are handles left over when a child was replaced (e.g. by a restart) without being
+    /// waited on. Each tick their `try_wait` is polled; terminated ones are dropped and reaped,
+    /// while those still shutting down are retained for the next tick.
+    fn reap_orphans(&mut self) {
+        self.orphans
+            .retain_mut(|child| matches!(child.try_wait(), Ok(None)));
+    }
+
+    /// Updates the shared PID set to reflect the currently live children.
+    ///
+    /// Kept in sync with the children so the signal-forwarding thread installed by
+    /// [`Supervisor::forward_signals`] always broadcasts to the right processes.
+    fn refresh_pids(&self) {
+        if let Ok(mut pids) = self.pids.lock() {
+            pids.clear();
+            pids.extend(
+                self.children
+                    .iter()
+                    .map(|supervised| unistd::Pid::from_raw(supervised.child.id() as i32)),
+            );
+        }
+    }
+
+    /// Installs signal handlers that forward termination signals to all live children.
+    ///
+    /// Like [`setup_term_flag`] this returns an atomic flag that is set on `SIGINT`, `SIGTERM` or
+    /// `SIGQUIT`, so the main loop can break and trigger shutdown. In addition, a background thread
+    /// re-broadcasts the received signal to every live child via [`signal::kill`], so a child that
+    /// only reacts to e.g. `SIGINT` sees it immediately instead of waiting for the shutdown
+    /// sequence to begin. The thread is stopped once the supervisor is shut down or dropped.
+    ///
+    /// Can be called more than once (e.g. to additionally react on a different flag); each call
+    /// starts its own thread and all of them are stopped together on shutdown.
+    pub fn forward_signals(&self) -> io::Result<sync::Arc<sync::atomic::AtomicBool>> {
+        let term = sync::Arc::new(sync::atomic::AtomicBool::new(false));
+        let signals = signal_hook::iterator::Signals::new([
+            signal_hook::SIGINT,
+            signal_hook::SIGTERM,
+            signal_hook::SIGQUIT,
+        ])?;
+
+        if let Ok(mut handles) = self.signal_handles.lock() {
+            handles.push(signals.clone());
+        }
+
+        let pids = self.pids.clone();
+        let thread_term = term.clone();
+        thread::spawn(move || {
+            for raw in signals.forever() {
+                let signal = match raw {
+                    signal_hook::SIGINT => signal::Signal::SIGINT,
+                    signal_hook::SIGTERM => signal::Signal::SIGTERM,
+                    signal_hook::SIGQUIT => signal::Signal::SIGQUIT,
+                    _ => continue,
+                };
+
+                if let Ok(pids) = pids.lock() {
+                    for &pid in pids.iter() {
+                        let _ = signal::kill(pid, signal);
+                    }
+                }
+
+                thread_term.store(true, sync::atomic::Ordering::Relaxed);
+            }
+        });
+
+        Ok(term)
+    }
+
+    /// Resolves the [`ShutdownConfig`] for a child, falling back to the supervisor-wide
+    /// `kill_timeout`/`poll_interval` and a `SIGTERM` when the child has none of its own.
+    fn config_for(&self, supervised: &SupervisedChild) -> ShutdownConfig {
+        supervised.shutdown.clone().unwrap_or(ShutdownConfig {
+            term_signal: signal::Signal::SIGTERM,
+            kill_timeout: Some(self.kill_timeout),
+            poll_interval: self.poll_interval,
+        })
+    }
+
+    /// Shuts down the child at `index` using its resolved [`ShutdownConfig`].
+    fn shutdown_at(&mut self, index: usize) -> io::Result<process::ExitStatus> {
+        let config = self.config_for(&self.children[index]);
+        shutdown_process_with(&mut self.children[index].child, &config)
+    }
+
+    /// Terminates all children, draining them so they are not shut down twice.
+    ///
+    /// Dispatches to the configured [`ShutdownOrder`].
+    fn shutdown_all(&mut self) -> Vec<(ChildId, io::Result<ExitOutcome>)> {
+        let children = std::mem::take(&mut self.children);
+        self.refresh_pids();
+
+        // Stop every signal-forwarding thread started via `forward_signals`: each `signals.forever()`
+        // loop would otherwise keep running (and keep re-signalling whatever PIDs are still in
+        // `self.pids`) for as long as the process lives, well past the supervisor itself.
+        if let Ok(mut handles) = self.signal_handles.lock() {
+            for signals in handles.drain(..) {
+                signals.close();
+            }
+        }
+
+        // Drain any outstanding orphans so their PIDs don't leak past shutdown.
+        for mut orphan in std::mem::take(&mut self.orphans) {
+            if let Ok(None) = orphan.try_wait() {
+                let _ = orphan.kill();
+                let _ = orphan.wait();
+            }
+        }
+
+        match self.order {
+            ShutdownOrder::Reverse => self.shutdown_all_reverse(children),
+            ShutdownOrder::Parallel => self.shutdown_all_parallel(children),
+        }
+    }
+
+    /// Terminates children one at a time, in the reverse order they were added.
+    fn shutdown_all_reverse(
+        &self,
+        mut children: Vec<SupervisedChild>,
+    ) -> Vec<(ChildId, io::Result<ExitOutcome>)> {
+        let mut outcomes = Vec::with_capacity(children.len());
+        for supervised in children.iter_mut().rev() {
+            let config = self.config_for(supervised);
+            let outcome = terminate(&mut supervised.child, &config).map(|(outcome, _)| outcome);
+            outcomes.push((supervised.id, outcome));
+        }
+        outcomes
+    }
+
+    /// Terminates all children concurrently.
+    ///
+    /// Signals every child up front, then polls them together, escalating each to `SIGKILL` only
+    /// once its own deadline passes. This preserves the per-child "ask nicely, then force"
+    /// semantics of [`shutdown_process_with`] while bounding total teardown to roughly one
+    /// `kill_timeout` regardless of child count.
+    fn shutdown_all_parallel(
+        &self,
+        children: Vec<SupervisedChild>,
+    ) -> Vec<(ChildId, io::Result<ExitOutcome>)> {
+        // A child still being torn down, along with everything needed to finish the job.
+        struct Pending {
+            id: ChildId,
+            child: process::Child,
+            config: ShutdownConfig,
+            // Deadline after which we escalate to SIGKILL, or `None` once escalation has happened.
+            deadline: Option<time::Instant>,
+            // Whether the child has already been SIGKILLed (so its exit counts as `Killed`).
+            killed: bool,
+        }
+
+        let start = time::Instant::now();
+        let mut outcomes: Vec<(ChildId, io::Result<ExitOutcome>)> = Vec::new();
+        let mut pending = Vec::with_capacity(children.len());
+
+        // Send the initial signal to every child up front.
+        for supervised in children {
+            let config = self.config_for(&supervised);
+            let SupervisedChild { id, mut child, .. } = supervised;
+
+            // The child may already have exited on its own (e.g. between the last poll tick and
+            // `shutdown`/`Drop` running); signalling its PID again would hit whatever the OS has
+            // since recycled it to. Mirrors the same check in `terminate`.
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    outcomes.push((id, Ok(ExitOutcome::Finished(status))));
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    outcomes.push((id, Err(e)));
+                    continue;
+                }
+            }
+
+            let pid = unistd::Pid::from_raw(child.id() as i32);
+
+            match config.kill_timeout {
+                // Brutal kill: SIGKILL straight away, then just wait for it to be reaped.
+                None => match signal::kill(pid, signal::Signal::SIGKILL) {
+                    Ok(()) => pending.push(Pending {
+                        id,
+                        child,
+                        config,
+                        deadline: None,
+                        killed: true,
+                    }),
+                    Err(e) => outcomes.push((id, Err(io::Error::new(io::ErrorKind::Other, e)))),
+                },
+                Some(kill_timeout) => match signal::kill(pid, config.term_signal) {
+                    Ok(()) => {
+                        let deadline = start + kill_timeout;
+                        pending.push(Pending {
+                            id,
+                            child,
+                            config,
+                            deadline: Some(deadline),
+                            killed: false,
+                        });
+                    }
+                    Err(e) => {
+                        let _ = child.kill();
+                        outcomes.push((id, Err(io::Error::new(io::ErrorKind::Other, e))));
+                    }
+                },
+            }
+        }
+
+        // Poll all remaining children together until each has exited.
+        while !pending.is_empty() {
+            let now = time::Instant::now();
+            let mut still_pending = Vec::with_capacity(pending.len());
+
+            for mut p in pending.drain(..) {
+                match p.child.try_wait() {
+                    Ok(Some(status)) => {
+                        let outcome = if p.killed {
+                            ExitOutcome::Killed
+                        } else {
+                            ExitOutcome::Finished(status)
+                        };
+                        outcomes.push((p.id, Ok(outcome)));
+                        continue;
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        outcomes.push((p.id, Err(e)));
+                        continue;
+                    }
+                }
+
+                // Still running: escalate to SIGKILL if its deadline has passed.
+                if !p.killed && p.deadline.is_none_or(|deadline| now >= deadline) {
+                    let pid = unistd::Pid::from_raw(p.child.id() as i32);
+                    if let Err(e) = signal::kill(pid, signal::Signal::SIGKILL) {
+                        outcomes.push((p.id, Err(io::Error::new(io::ErrorKind::Other, e))));
+                        continue;
+                    }
+                    p.killed = true;
+                    p.deadline = None;
+                }
+
+                still_pending.push(p);
+            }
+
+            pending = still_pending;
+            if let Some(interval) = pending.iter().map(|p| p.config.poll_interval).min() {
+                // Poll at the finest grain any still-pending child asked for, so a child with a
+                // short `poll_interval` isn't held up waiting on the supervisor-wide cadence.
+                thread::sleep(interval);
+            }
+        }
+
+        outcomes
+    }
+
+    /// Gracefully shuts down the supervisor, returning the outcome for each child.
+    ///
+    /// Children are terminated according to the configured [`ShutdownOrder`], and each result
+    /// distinguishes a clean
+    /// [`ExitOutcome::Finished`] from an [`ExitOutcome::Killed`] (required `SIGKILL` after the
+    /// grace period) or an [`io::Error`] raised while signalling. The returned vector is ordered
+    /// by [`ChildId`], i.e. the order children were added.
+    pub fn shutdown(mut self) -> Vec<(ChildId, io::Result<ExitOutcome>)> {
+        let mut outcomes = self.shutdown_all();
+        outcomes.sort_by_key(|(id, _)| id.0);
+        outcomes
     }
 }
 
 impl Drop for Supervisor {
     fn drop(&mut self) {
-        for child in self.children.iter_mut().rev() {
-            let _ = shutdown_process(child, self.kill_timeout, self.poll_interval);
-        }
+        // Delegates to the same termination path as `shutdown`, discarding the outcomes. Children
+        // already reaped by an explicit `shutdown` have been drained, so this is a no-op then.
+        let _ = self.shutdown_all();
     }
 }
 
@@ -63,6 +694,15 @@ impl Supervisor {
             children: Vec::new(),
             kill_timeout,
             poll_interval: time::Duration::from_millis(100),
+            strategy: Strategy::OneForOne,
+            intensity: 3,
+            period: time::Duration::from_secs(5),
+            restarts: Vec::new(),
+            next_id: 0,
+            pids: sync::Arc::new(sync::Mutex::new(Vec::new())),
+            order: ShutdownOrder::Reverse,
+            orphans: Vec::new(),
+            signal_handles: sync::Mutex::new(Vec::new()),
         }
     }
 }
@@ -82,24 +722,73 @@ pub fn shutdown_process(
     kill_timeout: time::Duration,
     poll_interval: time::Duration,
 ) -> io::Result<process::ExitStatus> {
-    let start = time::Instant::now();
+    shutdown_process_with(
+        child,
+        &ShutdownConfig {
+            term_signal: signal::Signal::SIGTERM,
+            kill_timeout: Some(kill_timeout),
+            poll_interval,
+        },
+    )
+}
+
+/// Shuts down a process according to a [`ShutdownConfig`].
+///
+/// Sends `config.term_signal` and polls the child for completion every `poll_interval`. If the
+/// process does not finish within `kill_timeout`, sends a `SIGKILL`. When `kill_timeout` is
+/// `None` the child is brutally killed, i.e. `SIGKILL` is sent straight away with no grace period.
+pub fn shutdown_process_with(
+    child: &mut process::Child,
+    config: &ShutdownConfig,
+) -> io::Result<process::ExitStatus> {
+    terminate(child, config).map(|(_, status)| status)
+}
+
+/// Terminates a process per `config`, reporting both the [`ExitOutcome`] and its exit status.
+///
+/// This is the shared core behind [`shutdown_process_with`] and the [`ShutdownOrder::Reverse`]
+/// teardown used by [`Supervisor::shutdown`] and `Drop`: the
+/// outcome distinguishes a child that exited within the grace period from one that had to be
+/// `SIGKILL`ed, while the status is always the final [`process::ExitStatus`].
+fn terminate(
+    child: &mut process::Child,
+    config: &ShutdownConfig,
+) -> io::Result<(ExitOutcome, process::ExitStatus)> {
+    // The child may already have been reaped (e.g. by a concurrent `try_wait` elsewhere); signalling
+    // its PID again would hit whatever the OS has since recycled it to.
+    if let Some(status) = child.try_wait()? {
+        return Ok((ExitOutcome::Finished(status), status));
+    }
+
     let pid = unistd::Pid::from_raw(child.id() as i32);
 
-    // Ask nicely via sigterm first.
-    signal::kill(pid, signal::Signal::SIGTERM)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let kill_timeout = match config.kill_timeout {
+        // Brutal kill: straight to SIGKILL, no grace period.
+        None => {
+            signal::kill(pid, signal::Signal::SIGKILL)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            return Ok((ExitOutcome::Killed, child.wait()?));
+        }
+        Some(kill_timeout) => kill_timeout,
+    };
+
+    let start = time::Instant::now();
+
+    // Ask nicely via the configured signal first.
+    signal::kill(pid, config.term_signal).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
     while time::Instant::now() - start < kill_timeout {
         if let Some(exit_status) = child.try_wait()? {
-            return Ok(exit_status);
+            return Ok((ExitOutcome::Finished(exit_status), exit_status));
         }
 
-        thread::sleep(poll_interval);
+        thread::sleep(config.poll_interval);
     }
 
     // If that fails, kill with SIGKILL.
     signal::kill(pid, signal::Signal::SIGKILL)
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    Ok(child.wait()?)
+    let status = child.wait()?;
+    Ok((ExitOutcome::Killed, status))
 }
 
 /// Sets up a termination flag.
@@ -134,3 +823,240 @@ pub fn setup_term_flag() -> Result<sync::Arc<sync::atomic::AtomicBool>, io::Erro
 
     Ok(term)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn sh(cmd: &str) -> process::Child {
+        Command::new("sh").arg("-c").arg(cmd).spawn().unwrap()
+    }
+
+    /// Spawns `sh -c "<setup>; echo ready; <cmd>"` and blocks until the readiness marker is
+    /// seen, so the caller can rely on `setup` (typically installing a `trap`) having actually
+    /// run before e.g. sending the child a signal that the trap is meant to catch.
+    fn sh_ready(setup: &str, cmd: &str) -> process::Child {
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(format!("{setup}; echo ready; {cmd}"))
+            .stdout(process::Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut stdout = child.stdout.take().unwrap();
+        let mut byte = [0u8; 1];
+        use std::io::Read;
+        while !matches!(stdout.read(&mut byte), Ok(0) | Err(_)) {
+            if byte[0] == b'\n' {
+                break;
+            }
+        }
+
+        child
+    }
+
+    /// Regression test for a bug where a dead `Temporary` child (no factory, never restarted)
+    /// blocked `run`'s scan loop from ever noticing deaths of children added after it, because
+    /// its cached exit status matched on every tick.
+    #[test]
+    fn run_keeps_restarting_children_added_after_a_dead_temporary_child() {
+        // A generous intensity budget: this test cares about restart detection past a dead
+        // Temporary sibling, not about the guard, and the spec child below restarts far more
+        // often than the default `intensity` tolerates within `period`.
+        let mut supervisor = Supervisor::default()
+            .with_max_restarts(1_000, time::Duration::from_millis(700));
+        supervisor.add_child(sh("true"), None);
+
+        let spawns = sync::Arc::new(sync::atomic::AtomicUsize::new(0));
+        let counter = spawns.clone();
+        supervisor
+            .add_spec(RestartPolicy::Permanent, move || {
+                counter.fetch_add(1, sync::atomic::Ordering::SeqCst);
+                Ok(sh("sleep 0.05; exit 1"))
+            })
+            .unwrap();
+
+        let term = sync::Arc::new(sync::atomic::AtomicBool::new(false));
+        let deadline = time::Instant::now() + time::Duration::from_millis(700);
+        let stop_term = term.clone();
+        thread::spawn(move || {
+            while time::Instant::now() < deadline {
+                thread::sleep(time::Duration::from_millis(10));
+            }
+            stop_term.store(true, sync::atomic::Ordering::Relaxed);
+        });
+
+        supervisor.run(&term).unwrap();
+
+        assert!(
+            spawns.load(sync::atomic::Ordering::SeqCst) > 1,
+            "expected the spec child to be restarted at least once"
+        );
+    }
+
+    /// The intensity guard should still trip (and tear everything down) when restarts are
+    /// happening, with a non-restarting sibling present ahead of the restarting one.
+    #[test]
+    fn intensity_guard_trips_with_mixed_policies_and_tears_down_remaining_children() {
+        let mut supervisor = Supervisor::new(time::Duration::from_millis(50))
+            .with_max_restarts(1, time::Duration::from_secs(5));
+        supervisor.add_child(sh("true"), None);
+        supervisor
+            .add_spec(RestartPolicy::Permanent, || Ok(sh("exit 1")))
+            .unwrap();
+
+        let term = sync::Arc::new(sync::atomic::AtomicBool::new(false));
+        assert!(supervisor.run(&term).is_err());
+        assert!(
+            supervisor
+                .children
+                .iter_mut()
+                .all(|supervised| matches!(supervised.child.try_wait(), Ok(Some(_)))),
+            "expected every remaining child to have been terminated once the guard tripped"
+        );
+    }
+
+    /// A child's own `ShutdownConfig` should override the supervisor-wide `kill_timeout`: a
+    /// brutal-kill child must go down immediately, even though it ignores `SIGTERM` and the
+    /// supervisor's own timeout is generous enough to have waited it out.
+    #[test]
+    fn shutdown_respects_per_child_shutdown_config() {
+        let mut supervisor = Supervisor::new(time::Duration::from_secs(10));
+        let id = supervisor.add_child(
+            sh("trap '' TERM; sleep 5"),
+            Some(ShutdownConfig {
+                term_signal: signal::Signal::SIGTERM,
+                kill_timeout: None,
+                poll_interval: time::Duration::from_millis(10),
+            }),
+        );
+
+        let start = time::Instant::now();
+        let outcomes = supervisor.shutdown();
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed < time::Duration::from_secs(1),
+            "brutal kill should not wait for the 10s supervisor-wide timeout, took {:?}",
+            elapsed
+        );
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].0, id);
+        assert!(matches!(outcomes[0].1, Ok(ExitOutcome::Killed)));
+    }
+
+    /// `shutdown` should distinguish a child that exits cleanly within the grace period from one
+    /// that had to be `SIGKILL`ed, per child, rather than reporting a single overall result.
+    #[test]
+    fn shutdown_reports_finished_and_killed_outcomes_per_child() {
+        let mut supervisor = Supervisor::new(time::Duration::from_millis(100));
+        let finishes = supervisor.add_child(sh("trap 'exit 0' TERM; sleep 5 & wait"), None);
+        let killed = supervisor.add_child(sh("trap '' TERM; sleep 5"), None);
+
+        let outcomes: std::collections::HashMap<_, _> = supervisor.shutdown().into_iter().collect();
+
+        assert!(matches!(
+            outcomes.get(&finishes).unwrap(),
+            Ok(ExitOutcome::Finished(_))
+        ));
+        assert!(matches!(outcomes.get(&killed).unwrap(), Ok(ExitOutcome::Killed)));
+    }
+
+    /// A signal received by the supervisor's own process should be re-broadcast to its live
+    /// children, not just flip the returned `term` flag.
+    #[test]
+    fn forward_signals_relays_received_signal_to_children() {
+        let mut supervisor = Supervisor::default();
+        supervisor.add_child(sh("sleep 5"), None);
+
+        let term = supervisor.forward_signals().unwrap();
+        signal::kill(unistd::getpid(), signal::Signal::SIGTERM).unwrap();
+
+        let deadline = time::Instant::now() + time::Duration::from_secs(1);
+        while time::Instant::now() < deadline
+            && !matches!(supervisor.children[0].child.try_wait(), Ok(Some(_)))
+        {
+            thread::sleep(time::Duration::from_millis(10));
+        }
+
+        assert!(
+            term.load(sync::atomic::Ordering::Relaxed),
+            "term flag should be set once SIGTERM is received"
+        );
+        assert!(
+            matches!(supervisor.children[0].child.try_wait(), Ok(Some(_))),
+            "child should have been killed by the forwarded SIGTERM"
+        );
+    }
+
+    /// Under `ShutdownOrder::Parallel`, children that ignore `SIGTERM` should be signalled
+    /// up front and escalate to `SIGKILL` together, bounding total teardown to roughly one
+    /// `kill_timeout` rather than `N * kill_timeout` as `Reverse` would. A child that already
+    /// exited on its own before `shutdown` runs must still be reported as `Finished`, not have
+    /// its (possibly recycled) PID signalled again.
+    #[test]
+    fn shutdown_all_parallel_is_concurrent_and_skips_already_exited_children() {
+        let mut supervisor = Supervisor::new(time::Duration::from_millis(200))
+            .with_shutdown_order(ShutdownOrder::Parallel);
+
+        let mut already_exited = sh("true");
+        while already_exited.try_wait().unwrap().is_none() {
+            thread::sleep(time::Duration::from_millis(5));
+        }
+        let exited_id = supervisor.add_child(already_exited, None);
+
+        let a = supervisor.add_child(sh_ready("trap '' TERM", "sleep 5"), None);
+        let b = supervisor.add_child(sh_ready("trap '' TERM", "sleep 5"), None);
+
+        let start = time::Instant::now();
+        let outcomes: std::collections::HashMap<_, _> = supervisor.shutdown().into_iter().collect();
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed < time::Duration::from_millis(600),
+            "parallel shutdown of two non-cooperating children took {:?}, expected roughly one kill_timeout",
+            elapsed
+        );
+        assert!(matches!(
+            outcomes.get(&exited_id).unwrap(),
+            Ok(ExitOutcome::Finished(_))
+        ));
+        assert!(matches!(outcomes.get(&a).unwrap(), Ok(ExitOutcome::Killed)));
+        assert!(matches!(outcomes.get(&b).unwrap(), Ok(ExitOutcome::Killed)));
+    }
+
+    /// Every restart replaces a child's handle and stashes the old one as an orphan so its PID
+    /// is reaped rather than leaked. `run`'s per-tick `reap_orphans` call should have cleared
+    /// them all out well before the loop exits.
+    #[test]
+    fn run_reaps_orphaned_children_left_behind_by_restarts() {
+        // A generous intensity budget: this test cares about orphan reaping, not the guard, and
+        // the spec child below restarts about every 100ms, comfortably past the default
+        // intensity within the default period. See
+        // `run_keeps_restarting_children_added_after_a_dead_temporary_child` for the same fix.
+        let mut supervisor = Supervisor::new(time::Duration::from_secs(10))
+            .with_max_restarts(1_000, time::Duration::from_millis(300));
+        supervisor
+            .add_spec(RestartPolicy::Permanent, || Ok(sh("exit 0")))
+            .unwrap();
+
+        let term = sync::Arc::new(sync::atomic::AtomicBool::new(false));
+        let deadline = time::Instant::now() + time::Duration::from_millis(300);
+        let stop_term = term.clone();
+        thread::spawn(move || {
+            while time::Instant::now() < deadline {
+                thread::sleep(time::Duration::from_millis(10));
+            }
+            stop_term.store(true, sync::atomic::Ordering::Relaxed);
+        });
+
+        supervisor.run(&term).unwrap();
+
+        assert!(
+            supervisor.orphans.is_empty(),
+            "expected every replaced child handle to have been reaped, found {}",
+            supervisor.orphans.len()
+        );
+    }
+}